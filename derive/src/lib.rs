@@ -90,10 +90,108 @@
 //!   - Done via `#[uncon(from_impl)]`.
 //!   - Only for C-like enums such that no variant is assigned a discriminant.
 //!
+//! Every generated `impl` is wrapped in
+//! `#[allow(trivial_casts, trivial_numeric_casts)]` so that crates using
+//! `#[deny(warnings)]` aren't broken by an `other(...)` type that happens to
+//! be a no-op cast away from the inner/repr type. A listed `other(...)` type
+//! that's identical to the inner/repr type is also skipped entirely, rather
+//! than emitting a redundant duplicate impl.
+//!
+//! - Derive a masking [`From`] for single-field structs:
+//!   - Done via `#[uncon(bits = N)]` or `#[uncon(mask = LIT)]`.
+//!   - `bits = N` masks the field to its low `N` bits; `mask = LIT` masks
+//!     with an arbitrary literal. Either generates `From<#field_ty>` (and,
+//!     combined with `other(...)`, `From` for every widened integer type) by
+//!     masking the input and forwarding to `from_unchecked`, so wrapper types
+//!     like a 4-bit `U4(u8)` no longer need a hand-written `From` impl:
+//!
+//!     ```
+//!     # #[macro_use] extern crate uncon_derive;
+//!     # extern crate uncon;
+//!     # use uncon::*;
+//!     #[derive(FromUnchecked)]
+//!     #[uncon(bits = 4)]
+//!     struct U4(u8);
+//!
+//!     # fn main() {
+//!     assert_eq!(U4::from(0b1111_0000u8).0, 0);
+//!     # }
+//!     ```
+//!
+//! # Const Conversions
+//!
+//! Alongside the `FromUnchecked` impl, the derive also generates an inherent
+//! `from_unchecked_const`, a `const fn` equivalent usable in `const` and
+//! `static` initializers, where trait methods can't (yet) be called.
+//!
+//! ```
+//! # #[macro_use] extern crate uncon_derive;
+//! # extern crate uncon;
+//! # use uncon::*;
+//! #[derive(FromUnchecked)]
+//! #[repr(u8)]
+//! enum Flag { A, B, C }
+//!
+//! const FLAG: Flag = unsafe { Flag::from_unchecked_const(1) };
+//! ```
+//!
+//! # Layout Assertions
+//!
+//! Every derived `FromUnchecked` impl is paired with a compile-time check
+//! that the type being transmuted into has the same size and alignment as
+//! the type being transmuted from, so picking a `#[repr]` that doesn't match
+//! is a hard compile error rather than silent undefined behavior.
+//!
+//! # Checked Conversions
+//!
+//! `#[derive(TryFrom)]` can be used on the same C-like enums to generate a
+//! checked counterpart instead: [`TryFromUnchecked`] and [`TryFrom`] impls
+//! that return `Err(InvalidValue(n))` for any integer `n` that doesn't match
+//! one of the enum's discriminants, rather than reinterpreting it regardless.
+//!
+//! ```
+//! # #[macro_use] extern crate uncon_derive;
+//! # extern crate uncon;
+//! # use std::convert::TryFrom;
+//! # use uncon::*;
+//! #[derive(TryFrom, PartialEq, Debug)]
+//! #[uncon(other(u16, u32, u64, usize))]
+//! # #[uncon(other(i8, i16, i32, i64, isize))]
+//! #[repr(u8)]
+//! enum Flag {
+//!     A, B, C, D
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(Flag::try_from(2u8), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(4u8), Err(InvalidValue(4u8)));
+//!
+//! // Done via `#[uncon(other(u16, u32, u64, usize))]`
+//! assert_eq!(Flag::try_from(2u16), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(256u16), Err(InvalidValue(256u16)));
+//! assert_eq!(Flag::try_from(2u32), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(256u32), Err(InvalidValue(256u32)));
+//! assert_eq!(Flag::try_from(2u64), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(256u64), Err(InvalidValue(256u64)));
+//! assert_eq!(Flag::try_from(2usize), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(256usize), Err(InvalidValue(256usize)));
+//!
+//! // Signed `other(...)` types must also reject negative values instead of
+//! // wrapping them into a valid-looking discriminant.
+//! assert_eq!(Flag::try_from(2i32), Ok(Flag::C));
+//! assert_eq!(Flag::try_from(-1i32), Err(InvalidValue(-1i32)));
+//! # }
+//! ```
+//!
+//! It also respects `#[uncon(other(...))]`, generating the same checked
+//! conversion for each listed integer type.
+//!
 //! [crate]: https://crates.io/crates/uncon_derive
 //! [`uncon`]: https://docs.rs/uncon
 //! [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+//! [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
 //! [`FromUnchecked`]: https://docs.rs/uncon/1.0.0/uncon/trait.FromUnchecked.html
+//! [`TryFromUnchecked`]: https://docs.rs/uncon/1.0.0/uncon/trait.TryFromUnchecked.html
 
 #[macro_use]
 extern crate quote;
@@ -101,7 +199,7 @@ extern crate proc_macro;
 extern crate syn;
 
 use proc_macro::TokenStream;
-use syn::{Body, MetaItem, NestedMetaItem, VariantData};
+use syn::{Body, ConstExpr, Lit, MetaItem, NestedMetaItem, VariantData};
 use quote::Tokens;
 
 #[doc(hidden)]
@@ -111,6 +209,13 @@ pub fn from_unchecked(input: TokenStream) -> TokenStream {
     impl_from_unchecked(&ast).parse().unwrap()
 }
 
+#[doc(hidden)]
+#[proc_macro_derive(TryFrom, attributes(uncon))]
+pub fn try_from(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_derive_input(&input.to_string()).unwrap();
+    impl_try_from_unchecked(&ast).parse().unwrap()
+}
+
 fn as_item(item: &NestedMetaItem) -> Option<&MetaItem> {
     if let NestedMetaItem::MetaItem(ref item) = *item {
         Some(item)
@@ -151,6 +256,89 @@ fn is_int_ty(s: &str) -> bool {
     }
 }
 
+fn enum_repr(ast: &syn::DeriveInput) -> Tokens {
+    let attr_items = |ident: &str| {
+        meta_items(ast.attrs.iter().map(|a| &a.value), ident)
+    };
+
+    let items = *attr_items("repr").first().expect("Could not find `#[repr]` attribute");
+
+    let repr = items.iter().filter_map(|ref item| {
+        if let NestedMetaItem::MetaItem(ref item) = **item {
+            let name = item.name();
+            if is_int_ty(name) {
+                return Some(name);
+            }
+        }
+        None
+    }).next().expect("Could not find integer repr for conversion");
+
+    let mut ty = Tokens::new();
+    ty.append(repr);
+    ty
+}
+
+fn name_value<'a>(items: &[&'a [NestedMetaItem]], ident: &str) -> Option<&'a Lit> {
+    items.iter().flat_map(|i| i.iter()).filter_map(|item| {
+        if let NestedMetaItem::MetaItem(MetaItem::NameValue(ref name, ref lit)) = *item {
+            if name == ident { return Some(lit); }
+        }
+        None
+    }).next()
+}
+
+fn discriminant_value(expr: &ConstExpr) -> Option<u64> {
+    match *expr {
+        ConstExpr::Lit(Lit::Int(value, _)) => Some(value),
+        _ => None,
+    }
+}
+
+// Validates `inner` (of type `compare_ty`) against the enum's discriminants
+// *before* narrowing it down to `repr`, so an out-of-range wide value (e.g.
+// `256u32` for a `u8`-repr enum) can't wrap back into the valid window.
+fn try_check(
+    core: &Tokens,
+    compare_ty: &Tokens,
+    repr: &Tokens,
+    num: usize,
+    discriminants: &[u64],
+    contiguous: bool,
+) -> Tokens {
+    if contiguous {
+        // A signed `compare_ty` also needs a lower bound: without it, any
+        // negative `inner` satisfies `inner < num` and would be transmuted
+        // into a bit pattern that isn't one of the enum's discriminants.
+        let signed = compare_ty.to_string().starts_with('i');
+        let lower_bound = if signed {
+            quote! { inner >= 0 && }
+        } else {
+            quote!()
+        };
+        quote! {
+            if #lower_bound inner < (#num as #compare_ty) {
+                ::#core::result::Result::Ok(::#core::mem::transmute(inner as #repr))
+            } else {
+                ::#core::result::Result::Err(::uncon::InvalidValue(inner))
+            }
+        }
+    } else {
+        let mut pat = Tokens::new();
+        for (i, discriminant) in discriminants.iter().enumerate() {
+            if i > 0 {
+                pat.append("|");
+            }
+            pat.append(discriminant.to_string());
+        }
+        quote! {
+            match inner {
+                #pat => ::#core::result::Result::Ok(::#core::mem::transmute(inner as #repr)),
+                _ => ::#core::result::Result::Err(::uncon::InvalidValue(inner)),
+            }
+        }
+    }
+}
+
 fn impl_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -181,21 +369,8 @@ fn impl_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
                 }
             }
 
-            let items = *attr_items("repr").first().expect("Could not find `#[repr]` attribute");
-
-            let repr = items.iter().filter_map(|ref item| {
-                if let NestedMetaItem::MetaItem(ref item) = **item {
-                    let name = item.name();
-                    if is_int_ty(name) {
-                        return Some(name);
-                    }
-                }
-                None
-            }).next().expect("Could not find integer repr for conversion");
-
             let init = quote! { ::#core::mem::transmute(inner) };
-            let mut ty = Tokens::new();
-            ty.append(repr);
+            let ty = enum_repr(ast);
 
             let from_impl = if impl_from {
                 let num = variants.len();
@@ -225,7 +400,26 @@ fn impl_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
             };
 
             let ty = &field.ty;
-            (quote!(#ty), init, None)
+
+            let bits = name_value(&uncon_items, "bits");
+            let mask = name_value(&uncon_items, "mask");
+            assert!(bits.is_none() || mask.is_none(),
+                    "Cannot specify both `bits` and `mask`");
+
+            let from_impl = if let Some(mask) = mask {
+                Some(quote! {
+                    unsafe { Self::from_unchecked(inner & (#mask as #ty)) }
+                })
+            } else if let Some(bits) = bits {
+                Some(quote! {
+                    const MASK: #ty = ((1 as #ty) << #bits) - 1;
+                    unsafe { Self::from_unchecked(inner & MASK) }
+                })
+            } else {
+                None
+            };
+
+            (quote!(#ty), init, from_impl)
         },
     };
 
@@ -239,13 +433,18 @@ fn impl_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
 
     let tys_impl = other_items.iter().filter_map(|item| {
         if let NestedMetaItem::MetaItem(MetaItem::Word(ref item)) = **item {
+            if item.to_string() == ty.to_string() {
+                return None;
+            }
             let from_impl = from_impl.as_ref().map(|_| quote! {
+                #[allow(trivial_casts, trivial_numeric_casts)]
                 impl #impl_generics From<#item> for #name #ty_generics #where_clause {
                     #[inline]
                     fn from(inner: #item) -> Self { (inner as #ty).into() }
                 }
             });
             Some(quote! {
+                #[allow(trivial_casts, trivial_numeric_casts)]
                 impl #impl_generics ::uncon::FromUnchecked<#item> for #name #ty_generics #where_clause {
                     #[inline]
                     unsafe fn from_unchecked(inner: #item) -> Self {
@@ -260,20 +459,168 @@ fn impl_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
     });
 
     let from_impl = from_impl.as_ref().map(|fi| quote! {
+        #[allow(trivial_casts, trivial_numeric_casts)]
         impl #impl_generics From<#ty> for #name #ty_generics #where_clause {
             #[inline]
             fn from(inner: #ty) -> Self { #fi }
         }
     });
 
+    let layout_assert = quote! {
+        const _: () = assert!(
+            ::#core::mem::size_of::<#ty>() == ::#core::mem::size_of::<#name #ty_generics>()
+        );
+        const _: () = assert!(
+            ::#core::mem::align_of::<#ty>() == ::#core::mem::align_of::<#name #ty_generics>()
+        );
+    };
+
     quote! {
+        #layout_assert
+
+        #[allow(trivial_casts, trivial_numeric_casts)]
         impl #impl_generics ::uncon::FromUnchecked<#ty> for #name #ty_generics #where_clause {
             #[inline]
             unsafe fn from_unchecked(inner: #ty) -> Self {
                 #init
             }
         }
+
+        #[allow(trivial_casts, trivial_numeric_casts)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Performs the unchecked conversion.
+            ///
+            /// This is the `const fn` equivalent of `FromUnchecked::from_unchecked`,
+            /// usable in `const` and `static` initializers.
+            #[inline]
+            pub const unsafe fn from_unchecked_const(inner: #ty) -> Self {
+                #init
+            }
+        }
         #from_impl
         #(#tys_impl)*
     }
 }
+
+fn impl_try_from_unchecked(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let attr_items = |ident: &str| {
+        meta_items(ast.attrs.iter().map(|a| &a.value), ident)
+    };
+    let uncon_items = attr_items("uncon");
+
+    let core = if cfg!(feature = "std") { quote!(std) } else { quote!(core) };
+
+    let variants = match ast.body {
+        Body::Enum(ref variants) => variants,
+        Body::Struct(_) => panic!("`TryFrom` can only be derived for C-like enums"),
+    };
+
+    for variant in variants {
+        match variant.data {
+            VariantData::Unit => continue,
+            _ => panic!("Found non-unit variant '{}'", variant.ident),
+        }
+    }
+
+    let ty = enum_repr(ast);
+
+    let mut next = 0u64;
+    let mut contiguous = true;
+    let discriminants: Vec<u64> = variants.iter().map(|variant| {
+        let value = match variant.discriminant {
+            Some(ref expr) => discriminant_value(expr)
+                .expect("Could not evaluate discriminant as an integer literal"),
+            None => next,
+        };
+        if value != next {
+            contiguous = false;
+        }
+        next = value + 1;
+        value
+    }).collect();
+
+    let num = discriminants.len();
+
+    let check = try_check(&core, &ty, &ty, num, &discriminants, contiguous);
+
+    let mut other_items = Vec::<&NestedMetaItem>::new();
+
+    for uncon_item in uncon_items.iter() {
+        for other_item in meta_items(uncon_item.iter().filter_map(as_item), "other") {
+            other_items.extend(other_item);
+        }
+    }
+
+    let tys_impl = other_items.iter().filter_map(|item| {
+        if let NestedMetaItem::MetaItem(MetaItem::Word(ref item)) = **item {
+            if item.to_string() == ty.to_string() {
+                return None;
+            }
+            // Validated against the full-width `item` type so an
+            // out-of-range value can't be truncated into validity first.
+            let item_ty = quote!(#item);
+            let other_check = try_check(&core, &item_ty, &ty, num, &discriminants, contiguous);
+            Some(quote! {
+                #[allow(trivial_casts, trivial_numeric_casts)]
+                impl #impl_generics ::uncon::TryFromUnchecked<#item> for #name #ty_generics #where_clause {
+                    type Error = ::uncon::InvalidValue<#item>;
+
+                    #[inline]
+                    fn try_from_unchecked(inner: #item) -> ::#core::result::Result<Self, Self::Error> {
+                        unsafe { #other_check }
+                    }
+                }
+
+                #[allow(trivial_casts, trivial_numeric_casts)]
+                impl #impl_generics ::#core::convert::TryFrom<#item> for #name #ty_generics #where_clause {
+                    type Error = ::uncon::InvalidValue<#item>;
+
+                    #[inline]
+                    fn try_from(inner: #item) -> ::#core::result::Result<Self, Self::Error> {
+                        ::uncon::TryFromUnchecked::try_from_unchecked(inner)
+                    }
+                }
+            })
+        } else {
+            None
+        }
+    });
+
+    let layout_assert = quote! {
+        const _: () = assert!(
+            ::#core::mem::size_of::<#ty>() == ::#core::mem::size_of::<#name #ty_generics>()
+        );
+        const _: () = assert!(
+            ::#core::mem::align_of::<#ty>() == ::#core::mem::align_of::<#name #ty_generics>()
+        );
+    };
+
+    quote! {
+        #layout_assert
+
+        #[allow(trivial_casts, trivial_numeric_casts)]
+        impl #impl_generics ::uncon::TryFromUnchecked<#ty> for #name #ty_generics #where_clause {
+            type Error = ::uncon::InvalidValue<#ty>;
+
+            #[inline]
+            fn try_from_unchecked(inner: #ty) -> ::#core::result::Result<Self, Self::Error> {
+                unsafe { #check }
+            }
+        }
+
+        #[allow(trivial_casts, trivial_numeric_casts)]
+        impl #impl_generics ::#core::convert::TryFrom<#ty> for #name #ty_generics #where_clause {
+            type Error = ::uncon::InvalidValue<#ty>;
+
+            #[inline]
+            fn try_from(inner: #ty) -> ::#core::result::Result<Self, Self::Error> {
+                ::uncon::TryFromUnchecked::try_from_unchecked(inner)
+            }
+        }
+
+        #(#tys_impl)*
+    }
+}
@@ -77,15 +77,26 @@
 //! let f = unsafe { Flags::from_unchecked(0b1100) };
 //! ```
 //!
+//! # Const Contexts
+//!
+//! Trait methods can't be called in a `const fn` or `static`/`const`
+//! initializer, so the handful of conversions here that are const-compatible
+//! also have a free-function equivalent: [`ref_from_unchecked`],
+//! [`ref_from_ptr`] and [`str_from_unchecked`]. The derive in
+//! [`uncon_derive`] generates an analogous `from_unchecked_const` inherent
+//! method alongside its `FromUnchecked` impl.
+//!
 //! # Safety
 //!
-//! - `Vec<U>` to `Vec<T>`, `Box<U>` to `Box<T>` and `&U` to `&T` conversions
-//!   are similar to [`mem::transmute`] except without the
-//!   [undefined behavior][ub]. There are absolutely **_no_** safety measures.
+//! - `Vec<U>` to `Vec<T>`, `Box<U>` to `Box<T>`, `&U` to `&T` and `[U; N]` to
+//!   `[T; N]` conversions are similar to [`mem::transmute`] except without
+//!   the [undefined behavior][ub]. There are absolutely **_no_** safety
+//!   measures.
 //!   - These conversions are extremely unsafe and should only be done in cases
 //!     such as turning `Vec<i8>` into `Vec<u8>` or something similarly trivial.
-//!   - If `T` implements `Drop` in the case of `Vec<T>`, consider `map`ping
-//!     `from_unchecked` and `collect`ing the results.
+//!   - If `T` implements `Drop` in the case of `Vec<T>` or `[T; N]`, consider
+//!     `map`ping `from_unchecked` and `collect`ing the results.
+//!   - `T` and `U` must share size and alignment; this isn't checked.
 //!
 //! [crate]: https://crates.io/crates/uncon
 //! [ub]: https://en.wikipedia.org/wiki/Undefined_behavior
@@ -93,6 +104,9 @@
 //! [`FromUnchecked`]: trait.FromUnchecked.html
 //! [`IntoUnchecked`]: trait.IntoUnchecked.html
 //! [`uncon_derive`]: https://docs.rs/uncon_derive
+//! [`ref_from_unchecked`]: fn.ref_from_unchecked.html
+//! [`ref_from_ptr`]: fn.ref_from_ptr.html
+//! [`str_from_unchecked`]: fn.str_from_unchecked.html
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "alloc", feature(alloc))]
@@ -117,7 +131,7 @@ use std::sync::Arc;
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::arc::Arc;
 
-use core::{mem, slice, str};
+use core::{fmt, mem, ptr, slice, str};
 
 /// Unchecked and potentially unsafe conversions from `T` into `Self`.
 pub trait FromUnchecked<T>: Sized {
@@ -131,6 +145,41 @@ pub trait IntoUnchecked<T>: Sized {
     unsafe fn into_unchecked(self) -> T;
 }
 
+/// Checked conversions from `T` into `Self` that reject values with no valid
+/// representation, rather than masking or wrapping them.
+///
+/// This is the fallible counterpart to [`FromUnchecked`](trait.FromUnchecked.html).
+pub trait TryFromUnchecked<T>: Sized {
+    /// The error returned when `T` has no valid representation as `Self`.
+    type Error;
+
+    /// Performs the checked conversion.
+    fn try_from_unchecked(value: T) -> Result<Self, Self::Error>;
+}
+
+/// The error returned when a value has no valid representation for the type
+/// being converted into.
+///
+/// This is produced by `#[derive(TryFrom)]` impls and carries the offending
+/// value so it can be inspected or reported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidValue<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for InvalidValue<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid value", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for InvalidValue<T> {
+    #[inline]
+    fn description(&self) -> &str {
+        "value out of range"
+    }
+}
+
 impl<T, U: FromUnchecked<T>> IntoUnchecked<U> for T {
     #[inline]
     unsafe fn into_unchecked(self) -> U {
@@ -145,6 +194,16 @@ impl<'a, T, U> FromUnchecked<&'a U> for &'a T {
     }
 }
 
+/// Const-evaluable equivalent of [`FromUnchecked::from_unchecked`] for
+/// `&U -> &T`, usable in `const fn` and `static`/`const` initializers where
+/// a trait method can't (yet) be called.
+///
+/// [`FromUnchecked::from_unchecked`]: trait.FromUnchecked.html#tymethod.from_unchecked
+#[inline]
+pub const unsafe fn ref_from_unchecked<'a, T, U>(other: &'a U) -> &'a T {
+    &*(other as *const U as *const T)
+}
+
 impl<'a, T, U> FromUnchecked<&'a mut U> for &'a mut T {
     #[inline]
     unsafe fn from_unchecked(other: &mut U) -> &mut T {
@@ -159,6 +218,16 @@ impl<'a, T: ?Sized> FromUnchecked<*const T> for &'a T {
     }
 }
 
+/// Const-evaluable equivalent of [`FromUnchecked::from_unchecked`] for
+/// `*const T -> &T`, usable in `const fn` and `static`/`const` initializers
+/// where a trait method can't (yet) be called.
+///
+/// [`FromUnchecked::from_unchecked`]: trait.FromUnchecked.html#tymethod.from_unchecked
+#[inline]
+pub const unsafe fn ref_from_ptr<'a, T: ?Sized>(ptr: *const T) -> &'a T {
+    &*ptr
+}
+
 impl<'a, T: ?Sized> FromUnchecked<*mut T> for &'a mut T {
     #[inline]
     unsafe fn from_unchecked(ptr: *mut T) -> &'a mut T {
@@ -180,6 +249,29 @@ impl<'a, T, U> FromUnchecked<&'a mut [U]> for &'a mut [T] {
     }
 }
 
+impl<T, U, const N: usize> FromUnchecked<[U; N]> for [T; N] {
+    #[inline]
+    unsafe fn from_unchecked(arr: [U; N]) -> [T; N] {
+        let out = ptr::read(&arr as *const [U; N] as *const [T; N]);
+        mem::forget(arr);
+        out
+    }
+}
+
+impl<'a, T, U, const N: usize> FromUnchecked<&'a [U; N]> for &'a [T; N] {
+    #[inline]
+    unsafe fn from_unchecked(arr: &[U; N]) -> &[T; N] {
+        &*(arr as *const [U; N] as *const [T; N])
+    }
+}
+
+impl<'a, T, U, const N: usize> FromUnchecked<&'a mut [U; N]> for &'a mut [T; N] {
+    #[inline]
+    unsafe fn from_unchecked(arr: &mut [U; N]) -> &mut [T; N] {
+        &mut *(arr as *mut [U; N] as *mut [T; N])
+    }
+}
+
 impl<'a> FromUnchecked<&'a [u8]> for &'a str {
     #[inline]
     unsafe fn from_unchecked(utf8: &[u8]) -> &str {
@@ -187,6 +279,16 @@ impl<'a> FromUnchecked<&'a [u8]> for &'a str {
     }
 }
 
+/// Const-evaluable equivalent of [`FromUnchecked::from_unchecked`] for
+/// `&[u8] -> &str`, usable in `const fn` and `static`/`const` initializers
+/// where a trait method can't (yet) be called.
+///
+/// [`FromUnchecked::from_unchecked`]: trait.FromUnchecked.html#tymethod.from_unchecked
+#[inline]
+pub const unsafe fn str_from_unchecked(utf8: &[u8]) -> &str {
+    str::from_utf8_unchecked(utf8)
+}
+
 impl<'a> FromUnchecked<&'a mut [u8]> for &'a mut str {
     #[inline]
     unsafe fn from_unchecked(utf8: &mut [u8]) -> &mut str {